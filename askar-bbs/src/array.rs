@@ -0,0 +1,73 @@
+use bls12_381::Scalar;
+
+use crate::commitment::CommittedMessages;
+use crate::error::Error;
+use crate::signature::{Message, SignatureMessages};
+
+/// A fixed-capacity, array-backed collection of up to `N` messages, usable
+/// as either [`SignatureMessages`] or [`CommittedMessages`] without
+/// requiring the `alloc` feature.
+///
+/// Each slot pairs a message with the generator index it is bound to; for
+/// plain signing (where the generator index always matches the message's
+/// position) messages can simply be pushed in order.
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayMessages<const N: usize> {
+    items: [(usize, Message); N],
+    len: usize,
+}
+
+impl<const N: usize> ArrayMessages<N> {
+    /// Create an empty, fixed-capacity collection.
+    pub fn new() -> Self {
+        Self {
+            items: [(0, Message::from(Scalar::from(0u64))); N],
+            len: 0,
+        }
+    }
+
+    /// Append a message bound to `generator_index`, returning an error if
+    /// the buffer is already at capacity `N`.
+    pub fn push(&mut self, generator_index: usize, message: Message) -> Result<(), Error> {
+        if self.len >= N {
+            return Err(err_msg!(ExceededBuffer, "array messages buffer is full"));
+        }
+        self.items[self.len] = (generator_index, message);
+        self.len += 1;
+        Ok(())
+    }
+}
+
+impl<const N: usize> Default for ArrayMessages<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> SignatureMessages for ArrayMessages<N> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn message(&self, index: usize) -> Option<Message> {
+        if index < self.len {
+            Some(self.items[index].1)
+        } else {
+            None
+        }
+    }
+}
+
+impl<const N: usize> CommittedMessages for ArrayMessages<N> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn committed(&self, index: usize) -> Option<(usize, Message)> {
+        if index < self.len {
+            Some(self.items[index])
+        } else {
+            None
+        }
+    }
+}
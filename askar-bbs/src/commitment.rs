@@ -0,0 +1,205 @@
+use bls12_381::{G1Affine, G1Projective, Scalar};
+use ff::Field;
+use group::Curve;
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::error::Error;
+use crate::generators::Generators;
+use crate::signature::Message;
+use crate::util::Nonce;
+
+/// A blinding factor hiding the messages inside a [`Commitment`].
+#[derive(Debug, Clone, Copy)]
+pub struct Blinding(pub(crate) Scalar);
+
+impl Blinding {
+    /// Draw a new random blinding factor from `rng`.
+    pub fn random(rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        Self(Scalar::random(rng))
+    }
+}
+
+impl From<Scalar> for Blinding {
+    fn from(scalar: Scalar) -> Self {
+        Self(scalar)
+    }
+}
+
+/// A fixed-size or heap-allocated collection of messages to be hidden
+/// behind a Pedersen [`Commitment`], paired with the indices of the
+/// generators they are bound to.
+///
+/// Implementations may be backed by a `Vec<(usize, Message)>` (see the
+/// `alloc` feature), a `[(usize, Message)]` slice, or a fixed-capacity
+/// [`ArrayMessages`](crate::array::ArrayMessages) on targets without `alloc`.
+pub trait CommittedMessages {
+    /// The number of committed messages.
+    fn len(&self) -> usize;
+
+    /// Whether this collection is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The generator index and message at position `index`, if present.
+    fn committed(&self, index: usize) -> Option<(usize, Message)>;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl CommittedMessages for Vec<(usize, Message)> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn committed(&self, index: usize) -> Option<(usize, Message)> {
+        self.as_slice().get(index).copied()
+    }
+}
+
+impl CommittedMessages for [(usize, Message)] {
+    fn len(&self) -> usize {
+        <[(usize, Message)]>::len(self)
+    }
+
+    fn committed(&self, index: usize) -> Option<(usize, Message)> {
+        self.get(index).copied()
+    }
+}
+
+/// A Pedersen commitment to a subset of messages, hidden behind a [`Blinding`]
+/// factor, to be later combined into a blind BBS+ signature request.
+#[derive(Debug, Clone, Copy)]
+pub struct Commitment(pub(crate) G1Affine);
+
+impl Commitment {
+    /// Commit to `messages` using `blinding` and the per-message generators
+    /// supplied by `generators`.
+    pub fn new(
+        generators: &impl Generators,
+        messages: &impl CommittedMessages,
+        blinding: &Blinding,
+    ) -> Result<Self, Error> {
+        let mut c = generators.blinding() * blinding.0;
+        for i in 0..messages.len() {
+            let (index, message) = messages
+                .committed(i)
+                .ok_or_else(|| err_msg!(Invalid, "missing committed message at index {}", i))?;
+            let h = generators
+                .message(index)
+                .ok_or_else(|| err_msg!(Invalid, "missing generator for message {}", index))?;
+            c += h * message.0;
+        }
+        Ok(Self(c.to_affine()))
+    }
+}
+
+/// A non-interactive Schnorr-style proof of knowledge of the blinding factor
+/// and the `N` messages hidden inside a [`Commitment`].
+///
+/// This is a multi-base proof of knowledge: the prover commits to fresh
+/// randomness for the blinding factor *and* for every one of the `N`
+/// committed messages, folds all of it into a single challenge, and returns
+/// one response per base. `N` must match the number of messages the
+/// [`Commitment`] was built from.
+#[derive(Debug, Clone, Copy)]
+pub struct CommitmentProof<const N: usize> {
+    challenge: Scalar,
+    blinding_response: Scalar,
+    message_responses: [(usize, Scalar); N],
+}
+
+impl<const N: usize> CommitmentProof<N> {
+    /// Prove knowledge of `blinding` and the `N` messages committed via
+    /// `messages` that produced `commitment`, binding the proof to `nonce`.
+    pub fn new(
+        generators: &impl Generators,
+        commitment: &Commitment,
+        messages: &impl CommittedMessages,
+        blinding: &Blinding,
+        nonce: &Nonce,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<Self, Error> {
+        if messages.len() != N {
+            return Err(err_msg!(
+                Invalid,
+                "expected {} committed messages, found {}",
+                N,
+                messages.len()
+            ));
+        }
+
+        let blinding_r = Scalar::random(&mut *rng);
+        let message_r: [Scalar; N] = core::array::from_fn(|_| Scalar::random(&mut *rng));
+
+        let mut announcement = generators.blinding() * blinding_r;
+        let mut indices = [0usize; N];
+        for i in 0..N {
+            let (index, _) = messages
+                .committed(i)
+                .ok_or_else(|| err_msg!(Invalid, "missing committed message at index {}", i))?;
+            let h = generators
+                .message(index)
+                .ok_or_else(|| err_msg!(Invalid, "missing generator for message {}", index))?;
+            announcement += h * message_r[i];
+            indices[i] = index;
+        }
+        let announcement = announcement.to_affine();
+
+        let challenge = Self::challenge(commitment, &announcement, nonce);
+        let blinding_response = blinding_r + challenge * blinding.0;
+
+        let mut message_responses = [(0usize, Scalar::from(0u64)); N];
+        for i in 0..N {
+            let (_, message) = messages.committed(i).expect("checked above");
+            message_responses[i] = (indices[i], message_r[i] + challenge * message.0);
+        }
+
+        Ok(Self {
+            challenge,
+            blinding_response,
+            message_responses,
+        })
+    }
+
+    /// Verify this proof against `commitment` and `nonce`.
+    pub fn verify(
+        &self,
+        generators: &impl Generators,
+        commitment: &Commitment,
+        nonce: &Nonce,
+    ) -> Result<(), Error> {
+        let mut announcement = generators.blinding() * self.blinding_response;
+        for (index, response) in self.message_responses {
+            let h = generators
+                .message(index)
+                .ok_or_else(|| err_msg!(Invalid, "missing generator for message {}", index))?;
+            announcement += h * response;
+        }
+        announcement -= G1Projective::from(commitment.0) * self.challenge;
+        let announcement = announcement.to_affine();
+
+        let challenge = Self::challenge(commitment, &announcement, nonce);
+        if challenge == self.challenge {
+            Ok(())
+        } else {
+            Err(err_msg!(Invalid, "commitment proof verification failed"))
+        }
+    }
+
+    fn challenge(commitment: &Commitment, announcement: &G1Affine, nonce: &Nonce) -> Scalar {
+        let mut hasher = Sha256::new();
+        hasher.update(commitment.0.to_compressed());
+        hasher.update(announcement.to_compressed());
+        hasher.update(nonce.to_bytes());
+        let digest = hasher.finalize();
+
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&digest);
+        Scalar::from_bytes_wide(&wide)
+    }
+}
@@ -0,0 +1,107 @@
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// The kind of a BBS+ operation error.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// An encoding or decoding operation failed.
+    Encoding,
+    /// A set of generators is too small for the number of messages supplied,
+    /// or a fixed-capacity message buffer is full.
+    ExceededBuffer,
+    /// The inputs provided to an operation were invalid, or a signature or
+    /// proof failed to verify.
+    Invalid,
+}
+
+impl ErrorKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Encoding => "Encoding error",
+            Self::ExceededBuffer => "Exceeded buffer capacity",
+            Self::Invalid => "Invalid data",
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A standard error type for BBS+ operations.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    #[cfg(feature = "alloc")]
+    message: Option<String>,
+}
+
+impl Error {
+    /// Create a new `Error` with the given kind and no extra detail.
+    pub fn from_kind(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            #[cfg(feature = "alloc")]
+            message: None,
+        }
+    }
+
+    /// Create a new `Error` with an explicit message, when `alloc` is available.
+    #[cfg(feature = "alloc")]
+    pub fn from_msg(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: Some(message.into()),
+        }
+    }
+
+    /// Accessor for the error kind.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        if let Some(message) = self.message.as_deref() {
+            return f.write_str(message);
+        }
+        Display::fmt(&self.kind, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Self::from_kind(kind)
+    }
+}
+
+/// Construct an [`Error`] from a kind, optionally with a formatted message when
+/// the `alloc` feature is enabled.
+macro_rules! err_msg {
+    ($kind:ident) => {
+        $crate::error::Error::from_kind($crate::error::ErrorKind::$kind)
+    };
+    ($kind:ident, $($args:tt)+) => {{
+        #[cfg(feature = "alloc")]
+        {
+            $crate::error::Error::from_msg(
+                $crate::error::ErrorKind::$kind,
+                alloc::format!($($args)+),
+            )
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            $crate::error::Error::from_kind($crate::error::ErrorKind::$kind)
+        }
+    }};
+}
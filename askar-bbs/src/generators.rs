@@ -0,0 +1,149 @@
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    G1Projective,
+};
+use sha2::Sha256;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+const DST_GENERATOR: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_generator_";
+
+/// Deterministically derive the generator for a given index by hashing to
+/// the BLS12-381 G1 curve.
+fn hash_to_generator(index: u64) -> G1Projective {
+    <G1Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(
+        index.to_be_bytes(),
+        DST_GENERATOR,
+    )
+}
+
+/// A source of the blinding generator and per-message generators used to
+/// construct and verify BBS+ signatures and commitments.
+///
+/// Implementations may compute generators on demand (see [`DynGeneratorsV1`])
+/// or cache them (see [`VecGenerators`] under the `alloc` feature, or
+/// [`ArrayGenerators`] for a fixed-capacity alternative that needs neither
+/// `alloc` nor `std`).
+pub trait Generators {
+    /// The blinding generator `h0`, applied to a signature's random `s` and
+    /// to a commitment's blinding factor.
+    fn blinding(&self) -> G1Projective;
+
+    /// The generator associated with the message at `index`, if this
+    /// instance supports that many messages.
+    fn message(&self, index: usize) -> Option<G1Projective>;
+
+    /// The maximum number of per-message generators this instance can supply.
+    fn capacity(&self) -> usize;
+}
+
+/// Generators derived on the fly by hashing to the BLS12-381 G1 curve.
+///
+/// This requires no storage and is available without the `alloc` feature,
+/// at the cost of recomputing each generator whenever it is used.
+#[derive(Debug, Clone, Copy)]
+pub struct DynGeneratorsV1 {
+    count: usize,
+}
+
+impl DynGeneratorsV1 {
+    /// Create a generator source supporting up to `count` messages.
+    pub fn new(count: usize) -> Self {
+        Self { count }
+    }
+}
+
+impl Generators for DynGeneratorsV1 {
+    fn blinding(&self) -> G1Projective {
+        hash_to_generator(0)
+    }
+
+    fn message(&self, index: usize) -> Option<G1Projective> {
+        if index < self.count {
+            Some(hash_to_generator(index as u64 + 1))
+        } else {
+            None
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.count
+    }
+}
+
+/// A precomputed, heap-allocated cache of generators, built from a
+/// [`DynGeneratorsV1`] source.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct VecGenerators {
+    blinding: G1Projective,
+    messages: Vec<G1Projective>,
+}
+
+#[cfg(feature = "alloc")]
+impl VecGenerators {
+    /// Precompute and cache `count` message generators.
+    pub fn new(count: usize) -> Self {
+        let source = DynGeneratorsV1::new(count);
+        let blinding = source.blinding();
+        let messages = (0..count)
+            .map(|i| source.message(i).expect("index in range"))
+            .collect();
+        Self { blinding, messages }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Generators for VecGenerators {
+    fn blinding(&self) -> G1Projective {
+        self.blinding
+    }
+
+    fn message(&self, index: usize) -> Option<G1Projective> {
+        self.messages.get(index).copied()
+    }
+
+    fn capacity(&self) -> usize {
+        self.messages.len()
+    }
+}
+
+/// A precomputed cache of generators backed by a fixed-size `[G1Projective; N]`
+/// array, for use on targets without `alloc`.
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayGenerators<const N: usize> {
+    blinding: G1Projective,
+    messages: [G1Projective; N],
+}
+
+impl<const N: usize> ArrayGenerators<N> {
+    /// Precompute and cache `N` message generators.
+    pub fn new() -> Self {
+        Self {
+            blinding: hash_to_generator(0),
+            messages: core::array::from_fn(|i| hash_to_generator(i as u64 + 1)),
+        }
+    }
+}
+
+impl<const N: usize> Default for ArrayGenerators<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Generators for ArrayGenerators<N> {
+    fn blinding(&self) -> G1Projective {
+        self.blinding
+    }
+
+    fn message(&self, index: usize) -> Option<G1Projective> {
+        self.messages.get(index).copied()
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+}
@@ -1,4 +1,5 @@
-// #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -11,10 +12,117 @@ mod commitment;
 pub use commitment::{Blinding, Commitment, CommitmentProof, CommittedMessages};
 
 mod generators;
-pub use generators::{DynGeneratorsV1, Generators, VecGenerators};
+pub use generators::{ArrayGenerators, DynGeneratorsV1, Generators};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use generators::VecGenerators;
 
 mod signature;
 pub use signature::{Message, Signature, SignatureMessages};
 
+mod array;
+pub use array::ArrayMessages;
+
 mod util;
 pub use util::Nonce;
+
+/// Commonly used types, re-exported for a single glob import:
+/// `use askar_bbs::prelude::*;`.
+pub mod prelude {
+    pub use crate::{
+        Blinding, Commitment, CommitmentProof, DynGeneratorsV1, Error, ErrorKind, Generators,
+        Message, Nonce, Signature, SignatureMessages,
+    };
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use bls12_381::{G2Projective, Scalar};
+    use ff::Field;
+    use group::Curve;
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn array_sign_verify_round_trip() {
+        let generators = ArrayGenerators::<2>::new();
+        let secret_key = Scalar::random(&mut OsRng);
+        let public_key = (G2Projective::generator() * secret_key).to_affine();
+
+        let mut messages = ArrayMessages::<2>::new();
+        messages.push(0, Message::from(Scalar::from(42u64))).unwrap();
+        messages.push(1, Message::from(Scalar::from(7u64))).unwrap();
+
+        let signature = Signature::new(&generators, &secret_key, &messages, &mut OsRng).unwrap();
+        signature
+            .verify(&generators, &public_key, &messages)
+            .unwrap();
+    }
+
+    #[test]
+    fn array_commit_prove_verify_round_trip() {
+        let generators = ArrayGenerators::<1>::new();
+
+        let mut messages = ArrayMessages::<1>::new();
+        messages.push(0, Message::from(Scalar::from(99u64))).unwrap();
+
+        let blinding = Blinding::random(&mut OsRng);
+        let commitment = Commitment::new(&generators, &messages, &blinding).unwrap();
+        let nonce = Nonce::from_rng(&mut OsRng);
+
+        let proof = CommitmentProof::<1>::new(
+            &generators,
+            &commitment,
+            &messages,
+            &blinding,
+            &nonce,
+            &mut OsRng,
+        )
+        .unwrap();
+
+        proof.verify(&generators, &commitment, &nonce).unwrap();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_sign_verify_round_trip() {
+        let generators = VecGenerators::new(2);
+        let secret_key = Scalar::random(&mut OsRng);
+        let public_key = (G2Projective::generator() * secret_key).to_affine();
+
+        let messages: alloc::vec::Vec<Message> = alloc::vec![
+            Message::from(Scalar::from(42u64)),
+            Message::from(Scalar::from(7u64)),
+        ];
+
+        let signature = Signature::new(&generators, &secret_key, &messages, &mut OsRng).unwrap();
+        signature
+            .verify(&generators, &public_key, &messages)
+            .unwrap();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_commit_prove_verify_round_trip() {
+        let generators = VecGenerators::new(1);
+
+        let messages: alloc::vec::Vec<(usize, Message)> =
+            alloc::vec![(0, Message::from(Scalar::from(99u64)))];
+
+        let blinding = Blinding::random(&mut OsRng);
+        let commitment = Commitment::new(&generators, &messages, &blinding).unwrap();
+        let nonce = Nonce::from_rng(&mut OsRng);
+
+        let proof = CommitmentProof::<1>::new(
+            &generators,
+            &commitment,
+            &messages,
+            &blinding,
+            &nonce,
+            &mut OsRng,
+        )
+        .unwrap();
+
+        proof.verify(&generators, &commitment, &nonce).unwrap();
+    }
+}
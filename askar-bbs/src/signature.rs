@@ -0,0 +1,150 @@
+use bls12_381::{G1Affine, G1Projective, G2Affine, Scalar};
+use ff::Field;
+use group::Curve;
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::error::Error;
+use crate::generators::Generators;
+
+/// A single message scalar, encoded for inclusion in a BBS+ signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Message(pub(crate) Scalar);
+
+impl From<Scalar> for Message {
+    fn from(scalar: Scalar) -> Self {
+        Self(scalar)
+    }
+}
+
+/// A fixed-size or heap-allocated collection of messages to be signed or
+/// verified together, paired with a compatible [`Generators`] instance.
+///
+/// Implementations may be backed by a `Vec<Message>` (see the `alloc`
+/// feature), a `[Message]` slice, or a fixed-capacity
+/// [`ArrayMessages`](crate::array::ArrayMessages) on targets without `alloc`.
+pub trait SignatureMessages {
+    /// The number of messages in this collection.
+    fn len(&self) -> usize;
+
+    /// Whether this collection is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The message at position `index`, if present.
+    fn message(&self, index: usize) -> Option<Message>;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl SignatureMessages for Vec<Message> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn message(&self, index: usize) -> Option<Message> {
+        self.as_slice().get(index).copied()
+    }
+}
+
+impl SignatureMessages for [Message] {
+    fn len(&self) -> usize {
+        <[Message]>::len(self)
+    }
+
+    fn message(&self, index: usize) -> Option<Message> {
+        self.get(index).copied()
+    }
+}
+
+/// A BBS+ signature over a fixed set of messages.
+#[derive(Debug, Clone, Copy)]
+pub struct Signature {
+    a: G1Affine,
+    e: Scalar,
+    s: Scalar,
+}
+
+impl Signature {
+    /// Sign `messages` using `secret_key`, deriving per-message generators
+    /// from `generators`. The caller supplies the source of randomness so
+    /// that signing remains usable without the standard library.
+    pub fn new(
+        generators: &impl Generators,
+        secret_key: &Scalar,
+        messages: &impl SignatureMessages,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<Self, Error> {
+        let count = messages.len();
+        if count > generators.capacity() {
+            return Err(err_msg!(
+                ExceededBuffer,
+                "not enough generators for {} messages",
+                count
+            ));
+        }
+
+        let e = Scalar::random(&mut *rng);
+        let s = Scalar::random(&mut *rng);
+
+        let mut b = G1Projective::generator() + generators.blinding() * s;
+        for i in 0..count {
+            let message = messages
+                .message(i)
+                .ok_or_else(|| err_msg!(Invalid, "missing message at index {}", i))?;
+            let h = generators
+                .message(i)
+                .ok_or_else(|| err_msg!(Invalid, "missing generator for message {}", i))?;
+            b += h * message.0;
+        }
+
+        let exp: Option<Scalar> = (secret_key + e).invert().into();
+        let exp = exp.ok_or_else(|| err_msg!(Invalid, "secret key and exponent collide"))?;
+        let a = (b * exp).to_affine();
+
+        Ok(Self { a, e, s })
+    }
+
+    /// Verify this signature over `messages` against `public_key`.
+    pub fn verify(
+        &self,
+        generators: &impl Generators,
+        public_key: &G2Affine,
+        messages: &impl SignatureMessages,
+    ) -> Result<(), Error> {
+        let count = messages.len();
+        if count > generators.capacity() {
+            return Err(err_msg!(
+                ExceededBuffer,
+                "not enough generators for {} messages",
+                count
+            ));
+        }
+
+        let mut b = G1Projective::generator() + generators.blinding() * self.s;
+        for i in 0..count {
+            let message = messages
+                .message(i)
+                .ok_or_else(|| err_msg!(Invalid, "missing message at index {}", i))?;
+            let h = generators
+                .message(i)
+                .ok_or_else(|| err_msg!(Invalid, "missing generator for message {}", i))?;
+            b += h * message.0;
+        }
+
+        let lhs = bls12_381::pairing(
+            &self.a,
+            &(G2Affine::generator() * self.e + public_key).to_affine(),
+        );
+        let rhs = bls12_381::pairing(&b.to_affine(), &G2Affine::generator());
+
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(err_msg!(Invalid, "signature verification failed"))
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use bls12_381::Scalar;
+use ff::Field;
+
+#[cfg(feature = "std")]
+use rand::rngs::OsRng;
+
+/// A random nonce used to bind a proof of knowledge to a particular
+/// verification challenge.
+#[derive(Debug, Clone, Copy)]
+pub struct Nonce(pub(crate) Scalar);
+
+impl Nonce {
+    /// Generate a new random nonce using the operating system RNG.
+    #[cfg(feature = "std")]
+    pub fn new() -> Self {
+        let mut rng = OsRng;
+        Self::from_rng(&mut rng)
+    }
+
+    /// Generate a new random nonce from an arbitrary CSPRNG.
+    pub fn from_rng(rng: &mut (impl rand_core::RngCore + rand_core::CryptoRng)) -> Self {
+        Self(Scalar::random(rng))
+    }
+
+    /// Construct a `Nonce` from a little-endian scalar encoding, returning
+    /// `None` if the bytes do not represent a canonical scalar.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        Scalar::from_bytes(bytes).map(Self).into()
+    }
+
+    /// Encode this nonce as a little-endian scalar.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for Nonce {
+    fn default() -> Self {
+        Self::new()
+    }
+}